@@ -26,46 +26,400 @@
 //! // We need to use `thread.join()` to get back any owned data
 //! assert_eq!(thread.join().unwrap(), "world");
 //! ```
+//!
+//! For threads whose *body* also needs to borrow from the environment, use
+//! [`scope_init`] instead, which joins every spawned thread before it
+//! returns.
 
+use std::any::Any;
+use std::cell::Cell;
+use std::fmt;
 use std::io;
+use std::marker::PhantomData;
+use std::panic;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::mpsc;
-use std::sync::mpsc::Sender;
+use std::sync::Arc;
+use std::sync::Once;
 use std::thread;
 use std::thread::JoinHandle;
+use std::time::Duration;
 
 /// A trait for spawning with borrowed initialization.
 pub trait SpawnInit {
-    fn spawn_init<F, G, T>(self, f: F) -> io::Result<JoinHandle<T>>
+    fn spawn_init<F, G, T>(self, f: F) -> Result<JoinHandle<T>, SpawnInitError>
     where
         F: Send + FnOnce() -> G,
         G: 'static + FnOnce() -> T,
         T: 'static + Send;
+
+    /// Like [`spawn_init`](SpawnInit::spawn_init), but the init closure can
+    /// also hand a value `R` back to the parent, instead of only releasing
+    /// its borrows. Useful when the new thread constructs something during
+    /// init that the parent needs, e.g. an `mpsc::Sender` or an OS handle.
+    fn spawn_init_with<F, G, R, T>(self, f: F) -> Result<(R, JoinHandle<T>), SpawnInitError>
+    where
+        F: Send + FnOnce() -> (R, G),
+        G: 'static + FnOnce() -> T,
+        R: Send,
+        T: 'static + Send;
+
+    /// Like [`spawn_init`](SpawnInit::spawn_init), but gives up waiting on
+    /// the init closure after `dur` instead of blocking forever.
+    ///
+    /// Unlike `spawn_init`, `f` must be `'static`: if init hasn't signalled
+    /// completion within `dur`, it may *still* be running, and there's no
+    /// safe way to hand borrows back while that's possibly true. Requiring
+    /// `'static` here means a stalled init can only leak the thread, never
+    /// race a borrow the caller thinks it has reclaimed -- see [`TimedOut`].
+    fn spawn_init_timeout<F, G, T>(
+        self,
+        dur: Duration,
+        f: F,
+    ) -> Result<JoinHandle<T>, SpawnInitTimeoutError<T>>
+    where
+        F: Send + 'static + FnOnce() -> G,
+        G: 'static + FnOnce() -> T,
+        T: 'static + Send;
 }
 
 impl SpawnInit for thread::Builder {
-    fn spawn_init<F, G, T>(self, f: F) -> io::Result<JoinHandle<T>>
+    fn spawn_init<F, G, T>(self, f: F) -> Result<JoinHandle<T>, SpawnInitError>
     where
         F: Send + FnOnce() -> G,
         G: 'static + FnOnce() -> T,
         T: 'static + Send,
     {
-        let (sender, receiver) = mpsc::channel();
-        let thread = unsafe {
-            self.spawn_unchecked(|| {
-                let g = {
-                    let _guard = Guard(sender);
-                    f()
-                };
-                g()
-            })
-        };
-        let _ = receiver.recv();
-        thread
+        unsafe { spawn_init_raw(self, f) }
+    }
+
+    fn spawn_init_with<F, G, R, T>(self, f: F) -> Result<(R, JoinHandle<T>), SpawnInitError>
+    where
+        F: Send + FnOnce() -> (R, G),
+        G: 'static + FnOnce() -> T,
+        R: Send,
+        T: 'static + Send,
+    {
+        unsafe { spawn_init_with_raw(self, f) }
+    }
+
+    fn spawn_init_timeout<F, G, T>(
+        self,
+        dur: Duration,
+        f: F,
+    ) -> Result<JoinHandle<T>, SpawnInitTimeoutError<T>>
+    where
+        F: Send + 'static + FnOnce() -> G,
+        G: 'static + FnOnce() -> T,
+        T: 'static + Send,
+    {
+        unsafe { spawn_init_timeout_raw(self, dur, f) }
+    }
+}
+
+/// The panic payload caught from an init closure that panicked, via
+/// [`std::panic::catch_unwind`].
+pub struct InitError(Box<dyn Any + Send + 'static>);
+
+impl InitError {
+    /// Recovers the panic payload, e.g. to forward it with
+    /// [`std::panic::resume_unwind`].
+    pub fn into_panic(self) -> Box<dyn Any + Send + 'static> {
+        self.0
+    }
+}
+
+impl fmt::Debug for InitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("InitError").finish()
+    }
+}
+
+impl fmt::Display for InitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "thread panicked during initialization")
+    }
+}
+
+impl std::error::Error for InitError {}
+
+/// The ways [`SpawnInit::spawn_init`] and [`SpawnInit::spawn_init_with`] can
+/// fail.
+#[derive(Debug)]
+pub enum SpawnInitError {
+    /// The OS failed to create the thread.
+    Spawn(io::Error),
+    /// The thread panicked while running its init closure, so the body
+    /// closure `G` never ran.
+    Init(InitError),
+}
+
+impl fmt::Display for SpawnInitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpawnInitError::Spawn(err) => write!(f, "failed to spawn thread: {err}"),
+            SpawnInitError::Init(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for SpawnInitError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SpawnInitError::Spawn(err) => Some(err),
+            SpawnInitError::Init(err) => Some(err),
+        }
+    }
+}
+
+/// The ways [`SpawnInit::spawn_init_timeout`] can fail.
+pub enum SpawnInitTimeoutError<T> {
+    /// Spawning the thread failed, or its init closure panicked within
+    /// `dur` -- same failure modes as [`SpawnInit::spawn_init`].
+    Failed(SpawnInitError),
+    /// The init closure hadn't finished within `dur`. See [`TimedOut`].
+    TimedOut(TimedOut<T>),
+}
+
+impl<T> fmt::Debug for SpawnInitTimeoutError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpawnInitTimeoutError::Failed(err) => f.debug_tuple("Failed").field(err).finish(),
+            SpawnInitTimeoutError::TimedOut(_) => f.debug_tuple("TimedOut").finish(),
+        }
+    }
+}
+
+impl<T> fmt::Display for SpawnInitTimeoutError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpawnInitTimeoutError::Failed(err) => write!(f, "{err}"),
+            SpawnInitTimeoutError::TimedOut(_) => write!(f, "thread initialization timed out"),
+        }
+    }
+}
+
+impl<T> std::error::Error for SpawnInitTimeoutError<T> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SpawnInitTimeoutError::Failed(err) => Some(err),
+            SpawnInitTimeoutError::TimedOut(_) => None,
+        }
+    }
+}
+
+/// Returned by [`SpawnInit::spawn_init_timeout`] when the init closure
+/// hasn't signalled completion within the requested duration.
+///
+/// `spawn_init_timeout` requires `f` to be `'static`, so there's nothing
+/// borrowed left for the still-possibly-running init closure to race with:
+/// dropping this value (including via `std::mem::forget`) without calling
+/// [`TimedOut::wait`] simply detaches the thread, the same as dropping a
+/// `JoinHandle` without joining it.
+pub struct TimedOut<T> {
+    receiver: mpsc::Receiver<Result<(), Box<dyn Any + Send>>>,
+    thread: JoinHandle<T>,
+}
+
+impl<T> fmt::Debug for TimedOut<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TimedOut").finish_non_exhaustive()
+    }
+}
+
+impl<T> TimedOut<T> {
+    /// Blocks until the init closure finishes, however long that takes, then
+    /// returns the thread's `JoinHandle` -- the same outcome a
+    /// `spawn_init_timeout` call that didn't time out would have produced.
+    pub fn wait(self) -> Result<JoinHandle<T>, SpawnInitError> {
+        match self.receiver.recv() {
+            Ok(Ok(())) => Ok(self.thread),
+            Ok(Err(payload)) => Err(SpawnInitError::Init(InitError(payload))),
+            Err(_) => Err(SpawnInitError::Init(InitError(Box::new(
+                "thread ended before completing initialization",
+            )))),
+        }
+    }
+}
+
+thread_local! {
+    // Set around the synthetic panic in `unwind_silently`, and checked by the
+    // wrapping hook installed by `ensure_silencing_hook_installed`. Being
+    // thread-local (rather than swapping the global hook itself), an
+    // unrelated thread panicking concurrently is unaffected: the previously
+    // installed hook runs for it exactly as if this crate weren't involved.
+    static SUPPRESS_PANIC_OUTPUT: Cell<bool> = const { Cell::new(false) };
+}
+
+// Wraps whatever panic hook is currently installed with one that also
+// consults `SUPPRESS_PANIC_OUTPUT`, so `unwind_silently` can suppress output
+// for its own synthetic panic without touching the global hook again (and
+// racing any other thread that panics while it's suppressed). Installed at
+// most once per process via `Once`, rather than per-panic.
+fn ensure_silencing_hook_installed() {
+    static INIT: Once = Once::new();
+    INIT.call_once(|| {
+        let prev_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !SUPPRESS_PANIC_OUTPUT.with(Cell::get) {
+                prev_hook(info);
+            }
+        }));
+    });
+}
+
+// Unwinds the current thread with a synthetic panic, without letting the
+// panic hook print anything for it. Used to terminate a thread after its
+// init closure's *real* panic payload has already been sent to the parent
+// (and already printed by the hook, as part of the `catch_unwind` above):
+// without this, the hook would also fire for this synthetic panic, printing
+// a second, unrelated-looking "thread initialization panicked" message to
+// stderr for what is logically one failure.
+fn unwind_silently() -> ! {
+    ensure_silencing_hook_installed();
+    SUPPRESS_PANIC_OUTPUT.with(|suppress| suppress.set(true));
+    let payload = panic::catch_unwind(|| panic!("thread initialization panicked")).unwrap_err();
+    SUPPRESS_PANIC_OUTPUT.with(|suppress| suppress.set(false));
+    panic::resume_unwind(payload);
+}
+
+// The guts of `spawn_init`, generic over the lifetime `'a` that the init and
+// body closures (and the result) are allowed to borrow for, rather than
+// hardcoding `'static`. This is what lets `Scope::spawn_init` reuse the same
+// init-phase handshake for threads that only need to live as long as a
+// lexical scope.
+//
+// The init closure `f` runs under `catch_unwind`, so a panic there is sent
+// back to the parent instead of silently letting the parent think init
+// succeeded; on that path the thread terminates without ever calling `g`.
+//
+// Safety: the caller must ensure the spawned thread is joined (or otherwise
+// prevented from observing freed data) before `'a` ends.
+unsafe fn spawn_init_raw<'a, F, G, T>(
+    builder: thread::Builder,
+    f: F,
+) -> Result<JoinHandle<T>, SpawnInitError>
+where
+    F: Send + 'a + FnOnce() -> G,
+    G: 'a + FnOnce() -> T,
+    T: Send + 'a,
+{
+    let (sender, receiver) = mpsc::channel();
+    let thread = builder
+        .spawn_unchecked(
+            move || match panic::catch_unwind(panic::AssertUnwindSafe(f)) {
+                Ok(g) => {
+                    let _ = sender.send(Ok(()));
+                    g()
+                }
+                Err(payload) => {
+                    let _ = sender.send(Err(payload));
+                    unwind_silently();
+                }
+            },
+        )
+        .map_err(SpawnInitError::Spawn)?;
+    match receiver.recv() {
+        Ok(Ok(())) => Ok(thread),
+        Ok(Err(payload)) => Err(SpawnInitError::Init(InitError(payload))),
+        Err(_) => Err(SpawnInitError::Init(InitError(Box::new(
+            "thread ended before completing initialization",
+        )))),
+    }
+}
+
+// The guts of `spawn_init_with`: same handshake as `spawn_init_raw`, except
+// the channel carries the init closure's `R` payload instead of pure `()`
+// signalling, so the parent gets it back alongside the `JoinHandle`.
+//
+// Safety: see `spawn_init_raw`.
+unsafe fn spawn_init_with_raw<'a, F, G, R, T>(
+    builder: thread::Builder,
+    f: F,
+) -> Result<(R, JoinHandle<T>), SpawnInitError>
+where
+    F: Send + 'a + FnOnce() -> (R, G),
+    G: 'a + FnOnce() -> T,
+    R: Send + 'a,
+    T: Send + 'a,
+{
+    let (sender, receiver) = mpsc::channel();
+    let thread = builder
+        .spawn_unchecked(
+            move || match panic::catch_unwind(panic::AssertUnwindSafe(f)) {
+                Ok((r, g)) => {
+                    let _ = sender.send(Ok(r));
+                    g()
+                }
+                Err(payload) => {
+                    let _ = sender.send(Err(payload));
+                    unwind_silently();
+                }
+            },
+        )
+        .map_err(SpawnInitError::Spawn)?;
+    match receiver.recv() {
+        Ok(Ok(r)) => Ok((r, thread)),
+        Ok(Err(payload)) => Err(SpawnInitError::Init(InitError(payload))),
+        Err(_) => Err(SpawnInitError::Init(InitError(Box::new(
+            "thread ended before completing initialization",
+        )))),
+    }
+}
+
+// Same handshake as `spawn_init_raw`, except the parent only waits `dur` for
+// the signal. On timeout, the thread (which may still be running the init
+// closure) is handed back wrapped in `TimedOut` rather than unwrapped, so
+// the caller can't mistake the timeout for init having released its
+// borrows. `f` is required to be `'static` here (unlike `spawn_init_raw`'s
+// generic `'a`) specifically so there are no such borrows to get this wrong
+// about: the worst a forgotten `TimedOut` can do is leak the thread.
+unsafe fn spawn_init_timeout_raw<F, G, T>(
+    builder: thread::Builder,
+    dur: Duration,
+    f: F,
+) -> Result<JoinHandle<T>, SpawnInitTimeoutError<T>>
+where
+    F: Send + 'static + FnOnce() -> G,
+    G: 'static + FnOnce() -> T,
+    T: 'static + Send,
+{
+    let (sender, receiver) = mpsc::channel();
+    let thread = builder
+        .spawn_unchecked(
+            move || match panic::catch_unwind(panic::AssertUnwindSafe(f)) {
+                Ok(g) => {
+                    let _ = sender.send(Ok(()));
+                    g()
+                }
+                Err(payload) => {
+                    let _ = sender.send(Err(payload));
+                    unwind_silently();
+                }
+            },
+        )
+        .map_err(|err| SpawnInitTimeoutError::Failed(SpawnInitError::Spawn(err)))?;
+    match receiver.recv_timeout(dur) {
+        Ok(Ok(())) => Ok(thread),
+        Ok(Err(payload)) => Err(SpawnInitTimeoutError::Failed(SpawnInitError::Init(
+            InitError(payload),
+        ))),
+        Err(mpsc::RecvTimeoutError::Timeout) => Err(SpawnInitTimeoutError::TimedOut(TimedOut {
+            receiver,
+            thread,
+        })),
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+            Err(SpawnInitTimeoutError::Failed(SpawnInitError::Init(
+                InitError(Box::new("thread ended before completing initialization")),
+            )))
+        }
     }
 }
 
 /// A helper function that tries to create a new thread with borrowed initialization.
-pub fn try_spawn<F, G, T>(f: F) -> io::Result<JoinHandle<T>>
+pub fn try_spawn<F, G, T>(f: F) -> Result<JoinHandle<T>, SpawnInitError>
 where
     F: Send + FnOnce() -> G,
     G: 'static + FnOnce() -> T,
@@ -84,12 +438,386 @@ where
     try_spawn(f).expect("Spawning failed")
 }
 
-// A guard that will send on the sender when it is dropped
-struct Guard(Sender<()>);
+/// A helper function that tries to create a new thread with borrowed
+/// initialization, getting back a value the init closure hands to the
+/// parent. See [`SpawnInit::spawn_init_with`].
+pub fn try_spawn_with<F, G, R, T>(f: F) -> Result<(R, JoinHandle<T>), SpawnInitError>
+where
+    F: Send + FnOnce() -> (R, G),
+    G: 'static + FnOnce() -> T,
+    R: Send,
+    T: 'static + Send,
+{
+    thread::Builder::new().spawn_init_with(f)
+}
+
+/// A helper function that creates a new thread with borrowed initialization,
+/// getting back a value the init closure hands to the parent. See
+/// [`SpawnInit::spawn_init_with`].
+pub fn spawn_with<F, G, R, T>(f: F) -> (R, JoinHandle<T>)
+where
+    F: Send + FnOnce() -> (R, G),
+    G: 'static + FnOnce() -> T,
+    R: Send,
+    T: 'static + Send,
+{
+    try_spawn_with(f).expect("Spawning failed")
+}
+
+/// A helper function that tries to create a new thread with borrowed
+/// initialization, giving up and reporting a stall if init doesn't finish
+/// within `dur`. See [`SpawnInit::spawn_init_timeout`].
+pub fn try_spawn_timeout<F, G, T>(
+    dur: Duration,
+    f: F,
+) -> Result<JoinHandle<T>, SpawnInitTimeoutError<T>>
+where
+    F: Send + 'static + FnOnce() -> G,
+    G: 'static + FnOnce() -> T,
+    T: 'static + Send,
+{
+    thread::Builder::new().spawn_init_timeout(dur, f)
+}
+
+/// A builder for threads spawned with borrowed initialization, offering the
+/// same `name`/`stack_size` configuration as `std::thread::Builder` for
+/// callers who would otherwise have to drop down to
+/// `thread::Builder::new().spawn_init(..)` directly.
+///
+/// Because the init closure already runs on the new thread, a name doesn't
+/// have to be fixed up front: call [`set_current_thread_name`] from inside
+/// `f` (e.g. after init has borrowed a config value) to name the thread from
+/// data that's only available once init is under way. [`Builder::name`]
+/// covers the common case of a name known before spawning.
+pub struct Builder {
+    inner: thread::Builder,
+    name: Option<String>,
+}
 
-impl Drop for Guard {
-    fn drop(&mut self) {
-        let _ = self.0.send(());
+impl Default for Builder {
+    fn default() -> Builder {
+        Builder::new()
+    }
+}
+
+impl Builder {
+    /// Creates a builder with the platform's default stack size and no name.
+    pub fn new() -> Builder {
+        Builder {
+            inner: thread::Builder::new(),
+            name: None,
+        }
+    }
+
+    /// Names the thread, both at the Rust level (as `std::thread::Builder::name`
+    /// does) and, once the thread starts, at the OS level via
+    /// [`set_current_thread_name`].
+    pub fn name(mut self, name: String) -> Builder {
+        self.inner = self.inner.name(name.clone());
+        self.name = Some(name);
+        self
+    }
+
+    /// Sets the size of the stack for the new thread, as
+    /// `std::thread::Builder::stack_size` does.
+    pub fn stack_size(mut self, size: usize) -> Builder {
+        self.inner = self.inner.stack_size(size);
+        self
+    }
+
+    /// Spawns a thread with borrowed initialization. See
+    /// [`SpawnInit::spawn_init`].
+    pub fn spawn_init<F, G, T>(self, f: F) -> Result<JoinHandle<T>, SpawnInitError>
+    where
+        F: Send + FnOnce() -> G,
+        G: 'static + FnOnce() -> T,
+        T: 'static + Send,
+    {
+        let name = self.name;
+        let f = move || {
+            if let Some(name) = &name {
+                set_current_thread_name(name);
+            }
+            f()
+        };
+        unsafe { spawn_init_raw(self.inner, f) }
+    }
+
+    /// Spawns a thread with borrowed initialization, getting back a value
+    /// the init closure hands to the parent. See
+    /// [`SpawnInit::spawn_init_with`].
+    pub fn spawn_init_with<F, G, R, T>(self, f: F) -> Result<(R, JoinHandle<T>), SpawnInitError>
+    where
+        F: Send + FnOnce() -> (R, G),
+        G: 'static + FnOnce() -> T,
+        R: Send,
+        T: 'static + Send,
+    {
+        let name = self.name;
+        let f = move || {
+            if let Some(name) = &name {
+                set_current_thread_name(name);
+            }
+            f()
+        };
+        unsafe { spawn_init_with_raw(self.inner, f) }
+    }
+}
+
+/// Sets the OS-visible name (e.g. the one `/proc/<pid>/task/<tid>/comm` and
+/// debuggers show) of the thread calling this function. Unlike
+/// `std::thread::Builder::name`, this takes effect on the *current* thread
+/// immediately, so it can be called from inside a `spawn_init` init closure
+/// using data that's only available once the thread has started -- e.g. a
+/// borrowed config field.
+pub fn set_current_thread_name(name: &str) {
+    os::set_current_thread_name(name);
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod os {
+    use std::ffi::CString;
+
+    // Linux's `TASK_COMM_LEN` is 16 *bytes* including the trailing NUL.
+    const MAX_LEN: usize = 15;
+
+    // Truncate by byte length, not char count: `TASK_COMM_LEN` is a byte
+    // limit, and a name that "fits" by char count can still be too many
+    // bytes once multi-byte UTF-8 characters are involved, which makes
+    // `pthread_setname_np` fail (silently, since renaming is best-effort)
+    // instead of truncating as intended.
+    fn truncate_to_os_limit(name: &str) -> &str {
+        let mut len = name.len().min(MAX_LEN);
+        while !name.is_char_boundary(len) {
+            len -= 1;
+        }
+        &name[..len]
+    }
+
+    pub(crate) fn set_current_thread_name(name: &str) {
+        let Ok(name) = CString::new(truncate_to_os_limit(name)) else {
+            return;
+        };
+        extern "C" {
+            fn pthread_self() -> usize;
+            fn pthread_setname_np(thread: usize, name: *const std::os::raw::c_char) -> i32;
+        }
+        unsafe {
+            let _ = pthread_setname_np(pthread_self(), name.as_ptr());
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        #[test]
+        fn truncate_to_os_limit_multibyte_test() {
+            // 15 *characters*, each 2 bytes in UTF-8 ("é" U+00E9): this would
+            // fit a char-counting truncation but is 30 bytes, well over
+            // `TASK_COMM_LEN`. The truncated result must still land on a
+            // char boundary and fit within the byte limit.
+            let name = "é".repeat(15);
+            let truncated = super::truncate_to_os_limit(&name);
+            assert!(truncated.len() <= super::MAX_LEN);
+            assert!(name.is_char_boundary(truncated.len()));
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod os {
+    use std::ffi::CString;
+
+    pub(crate) fn set_current_thread_name(name: &str) {
+        let Ok(name) = CString::new(name) else {
+            return;
+        };
+        extern "C" {
+            fn pthread_setname_np(name: *const std::os::raw::c_char) -> i32;
+        }
+        unsafe {
+            let _ = pthread_setname_np(name.as_ptr());
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android", target_os = "macos")))]
+mod os {
+    pub(crate) fn set_current_thread_name(_name: &str) {
+        // No portable way to rename an already-running thread on this
+        // platform; the name passed to `Builder::name` still shows up in
+        // panic messages via the Rust-level thread name.
+    }
+}
+
+/// A scope for spawning borrowed-init threads whose bodies may also borrow
+/// from the environment, created by [`scope_init`].
+///
+/// This sits between [`spawn`] (init-only borrows, `'static` body) and a
+/// fully blocking scoped thread: the body closure `G` returned from `f` may
+/// borrow from `'env`, and every thread spawned through this scope is joined
+/// before `scope_init` returns, so those borrows stay valid for as long as
+/// the scope needs them.
+pub struct Scope<'scope, 'env: 'scope> {
+    data: Arc<ScopeData>,
+    // Invariant over 'scope, to make sure 'scope cannot shrink, which is
+    // necessary for soundness.
+    scope: PhantomData<&'scope mut &'scope ()>,
+    env: PhantomData<&'env mut &'env ()>,
+}
+
+// `ScopeData` carries no borrowed data of its own (only a count, a flag, and
+// the waiting thread's handle), so it's free of `'scope`: that's what lets
+// the spawned threads hold an `Arc` clone of it without entangling `'scope`
+// in their return type.
+struct ScopeData {
+    num_running_threads: AtomicUsize,
+    // Set when a scoped thread's body panics, so `scope_init` can raise that
+    // panic itself even if the caller never joined the `ScopedJoinHandle`
+    // that would otherwise have reported it.
+    a_thread_panicked: AtomicBool,
+    main_thread: thread::Thread,
+}
+
+impl ScopeData {
+    fn decrement_num_running_threads(&self, panicked: bool) {
+        if panicked {
+            self.a_thread_panicked.store(true, Ordering::Relaxed);
+        }
+        if self.num_running_threads.fetch_sub(1, Ordering::Release) == 1 {
+            self.main_thread.unpark();
+        }
+    }
+}
+
+impl<'scope, 'env> Scope<'scope, 'env> {
+    /// Spawns a thread whose init closure `f` may borrow from `'env`, and
+    /// whose body closure `G` may *also* borrow from `'env` for the
+    /// remainder of the scope.
+    ///
+    /// Unlike [`SpawnInit::spawn_init`], the returned [`ScopedJoinHandle`]
+    /// does not have to be joined by the caller: if it is still outstanding
+    /// when the [`scope_init`] closure returns, the scope joins it then.
+    pub fn spawn_init<F, G, T>(&'scope self, f: F) -> ScopedJoinHandle<'scope, T>
+    where
+        F: Send + 'scope + FnOnce() -> G,
+        G: Send + 'scope + FnOnce() -> T,
+        T: Send + 'scope,
+    {
+        self.data
+            .num_running_threads
+            .fetch_add(1, Ordering::Relaxed);
+        let data = Arc::clone(&self.data);
+        let f = move || {
+            let g = f();
+            move || {
+                // Catch a panicking body so we can always decrement (and, if
+                // it did panic, record that in `a_thread_panicked` before
+                // `scope_init`'s wait loop can observe the decremented
+                // count), then resume the unwind so the panic still reaches
+                // this thread's `JoinHandle` as usual.
+                let result = panic::catch_unwind(panic::AssertUnwindSafe(g));
+                data.decrement_num_running_threads(result.is_err());
+                match result {
+                    Ok(value) => value,
+                    Err(payload) => panic::resume_unwind(payload),
+                }
+            }
+        };
+        // Safety: `scope_init` parks until every thread spawned through this
+        // scope has decremented `num_running_threads`, which only happens
+        // after the body closure above has returned or unwound. So the
+        // thread cannot outlive the borrows captured by `f` and `G`.
+        let handle = match unsafe { spawn_init_raw(thread::Builder::new(), f) } {
+            Ok(handle) => handle,
+            Err(err) => {
+                // The thread never got far enough to construct the body
+                // closure, so nothing above accounts for the increment above
+                // -- undo it ourselves before propagating. This isn't a body
+                // panic, so it doesn't set `a_thread_panicked`: it unwinds
+                // straight out of this call, which is inside the caller's
+                // `f`, so `scope_init`'s own `catch_unwind` around `f`
+                // reports it already.
+                self.data.decrement_num_running_threads(false);
+                match err {
+                    SpawnInitError::Spawn(err) => panic!("Spawning failed: {err}"),
+                    SpawnInitError::Init(err) => panic::resume_unwind(err.into_panic()),
+                }
+            }
+        };
+        ScopedJoinHandle {
+            handle,
+            scope: PhantomData,
+        }
+    }
+}
+
+/// An owned handle to a thread spawned into a [`Scope`], returned by
+/// [`Scope::spawn_init`].
+///
+/// Dropping this handle without calling [`ScopedJoinHandle::join`] is fine:
+/// the enclosing [`scope_init`] call waits for the thread on the caller's
+/// behalf.
+pub struct ScopedJoinHandle<'scope, T> {
+    handle: JoinHandle<T>,
+    scope: PhantomData<&'scope ()>,
+}
+
+impl<'scope, T> ScopedJoinHandle<'scope, T> {
+    /// Waits for the thread to finish, returning its result.
+    pub fn join(self) -> thread::Result<T> {
+        self.handle.join()
+    }
+}
+
+/// Creates a [`Scope`] for spawning borrowed-init threads, analogous to
+/// `std::thread::scope`. All threads spawned via [`Scope::spawn_init`] are
+/// joined before `scope_init` returns, whether or not the caller joined them
+/// explicitly.
+///
+/// For example:
+/// ```rust
+/// let mut count = 0;
+/// thread_init::scope_init(|s| {
+///     let handle = s.spawn_init(|| {
+///         // Borrows during init...
+///         let count = &count;
+///         move || {
+///             // ...and during the body.
+///             assert_eq!(*count, 0);
+///         }
+///     });
+///     handle.join().unwrap();
+/// });
+/// count += 1;
+/// assert_eq!(count, 1);
+/// ```
+pub fn scope_init<'env, F, T>(f: F) -> T
+where
+    F: for<'scope> FnOnce(&'scope Scope<'scope, 'env>) -> T,
+{
+    let scope = Scope {
+        data: Arc::new(ScopeData {
+            num_running_threads: AtomicUsize::new(0),
+            a_thread_panicked: AtomicBool::new(false),
+            main_thread: thread::current(),
+        }),
+        scope: PhantomData,
+        env: PhantomData,
+    };
+    // Catch a panicking `f` so we always reach the wait loop below: without
+    // this, unwinding straight out of `scope_init` could drop `'env` data
+    // that sibling threads spawned earlier in `f` are still borrowing for
+    // their body closures.
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| f(&scope)));
+    while scope.data.num_running_threads.load(Ordering::Acquire) != 0 {
+        thread::park();
+    }
+    match result {
+        Err(payload) => panic::resume_unwind(payload),
+        Ok(_) if scope.data.a_thread_panicked.load(Ordering::Relaxed) => {
+            panic!("a scoped thread panicked")
+        }
+        Ok(result) => result,
     }
 }
 
@@ -110,4 +838,110 @@ mod tests {
         assert_eq!(hello, "hello");
         assert_eq!(thread.join().unwrap(), "world");
     }
+
+    #[test]
+    fn spawn_with_test() {
+        let ref hello = String::from("hello");
+        let (sender, thread) = crate::spawn_with(move || {
+            let hi = hello.clone();
+            let (sender, receiver) = std::sync::mpsc::channel();
+            (sender, move || {
+                assert_eq!(hi, "hello");
+                receiver.recv().unwrap()
+            })
+        });
+        assert_eq!(hello, "hello");
+        sender.send("world").unwrap();
+        assert_eq!(thread.join().unwrap(), "world");
+    }
+
+    #[test]
+    fn spawn_init_panic_test() {
+        let err = crate::try_spawn(|| -> fn() -> () {
+            panic!("oh no");
+        })
+        .expect_err("init panic should be reported to the parent");
+        assert!(matches!(err, crate::SpawnInitError::Init(_)));
+    }
+
+    #[test]
+    fn builder_name_test() {
+        let ref name = String::from("alice");
+        let thread = crate::Builder::new()
+            .name(name.clone())
+            .spawn_init(move || {
+                assert_eq!(std::thread::current().name(), Some(name.as_str()));
+                move || ()
+            })
+            .unwrap();
+        thread.join().unwrap();
+    }
+
+    #[test]
+    fn spawn_init_timeout_test() {
+        let (release_sender, release_receiver) = std::sync::mpsc::channel::<()>();
+        match crate::try_spawn_timeout(std::time::Duration::from_millis(10), move || {
+            // Blocks past the timeout until the test lets it through.
+            release_receiver.recv().unwrap();
+            move || ()
+        }) {
+            Err(crate::SpawnInitTimeoutError::TimedOut(timed_out)) => {
+                release_sender.send(()).unwrap();
+                timed_out.wait().unwrap().join().unwrap();
+            }
+            other => panic!("expected a timeout, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn scope_init_test() {
+        let numbers = [1, 2, 3];
+        let sum = std::sync::atomic::AtomicUsize::new(0);
+        crate::scope_init(|s| {
+            let sum = &sum;
+            let handles: Vec<_> = numbers
+                .iter()
+                .map(|n| {
+                    s.spawn_init(move || {
+                        // Borrows during init...
+                        let n = *n;
+                        move || {
+                            // ...and during the body.
+                            sum.fetch_add(n, std::sync::atomic::Ordering::Relaxed);
+                        }
+                    })
+                })
+                .collect();
+            // Join some explicitly, but leave the rest -- `scope_init` must
+            // join those on our behalf before it returns.
+            handles.into_iter().next().unwrap().join().unwrap();
+        });
+        assert_eq!(sum.load(std::sync::atomic::Ordering::Relaxed), 6);
+    }
+
+    #[test]
+    fn scope_init_spawn_panic_test() {
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            crate::scope_init(|s| {
+                s.spawn_init(|| -> fn() -> () { panic!("oh no") });
+            });
+        }));
+        assert!(result.is_err(), "a panicking init should unwind scope_init");
+    }
+
+    #[test]
+    fn scope_init_body_panic_unjoined_test() {
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            crate::scope_init(|s| {
+                // Dropped without ever calling `.join()` -- `scope_init`
+                // must still notice the body panicked and report it, the
+                // same way `std::thread::scope` does.
+                let _handle = s.spawn_init(|| move || panic!("oh no"));
+            });
+        }));
+        assert!(
+            result.is_err(),
+            "an unjoined scoped thread's body panic should unwind scope_init"
+        );
+    }
 }